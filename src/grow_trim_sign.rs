@@ -0,0 +1,248 @@
+//! Property-decomposed cast traits.
+//!
+//! A raw `as` cast bundles three independent properties: widening
+//! ([`GrowFrom`]), narrowing ([`TrimFrom`]), and sign reinterpretation
+//! ([`SignCast`]). Each trait here isolates exactly one of them, so generic
+//! bounds can require precisely the property a cast needs.
+
+/// Widen from one type to another without losing information.
+///
+/// Mirrors [`CastFrom`](crate::CastFrom), but is only implemented for
+/// conversions that can never lose information: growing to a wider integer
+/// of the same signedness, growing from an unsigned integer to a strictly
+/// wider signed integer, or growing to a wider float.
+///
+/// # Example
+///
+/// ```
+/// use casting::GrowFrom;
+///
+/// let x: u8 = 42;
+/// let y = u16::grow_from(x);
+/// assert_eq!(y, 42u16);
+/// ```
+pub trait GrowFrom<T> {
+    /// Widens `value` from type `T` to `Self`.
+    fn grow_from(value: T) -> Self;
+}
+
+/// Widen into another type.
+///
+/// Mirrors [`CastInto`](crate::CastInto) but for [`GrowFrom`]. Automatically
+/// implemented for all types that implement [`GrowFrom`].
+///
+/// **Do not implement this trait directly.** Implement [`GrowFrom`] instead.
+///
+/// # Example
+///
+/// ```
+/// use casting::GrowInto;
+///
+/// let x: u8 = 42;
+/// let y: u16 = x.grow_into();
+/// assert_eq!(y, 42u16);
+/// ```
+pub trait GrowInto<T> {
+    /// Widens `self` into type `T`.
+    fn grow_into(self) -> T;
+}
+
+impl<T, U: GrowFrom<T>> GrowInto<U> for T {
+    fn grow_into(self) -> U {
+        U::grow_from(self)
+    }
+}
+
+/// Narrow from one type to another, truncating as `as` would.
+///
+/// Mirrors [`CastFrom`](crate::CastFrom), but is only implemented for
+/// conversions that narrow: to a smaller integer of the same signedness, or
+/// to a smaller float. The conversion is lossy by nature; values outside the
+/// target's range are truncated exactly like an `as` cast.
+///
+/// # Example
+///
+/// ```
+/// use casting::TrimFrom;
+///
+/// let x: u32 = 300;
+/// let y = u8::trim_from(x);
+/// assert_eq!(y, 300u32 as u8);
+/// ```
+pub trait TrimFrom<T> {
+    /// Narrows `value` from type `T` to `Self`, truncating as `as` would.
+    fn trim_from(value: T) -> Self;
+}
+
+/// Narrow into another type.
+///
+/// Mirrors [`CastInto`](crate::CastInto) but for [`TrimFrom`]. Automatically
+/// implemented for all types that implement [`TrimFrom`].
+///
+/// **Do not implement this trait directly.** Implement [`TrimFrom`] instead.
+///
+/// # Example
+///
+/// ```
+/// use casting::TrimInto;
+///
+/// let x: u32 = 300;
+/// let y: u8 = x.trim_into();
+/// assert_eq!(y, 300u32 as u8);
+/// ```
+pub trait TrimInto<T> {
+    /// Narrows `self` into type `T`, truncating as `as` would.
+    fn trim_into(self) -> T;
+}
+
+impl<T, U: TrimFrom<T>> TrimInto<U> for T {
+    fn trim_into(self) -> U {
+        U::trim_from(self)
+    }
+}
+
+/// Reinterpret a value as the opposite-signedness integer of the same width.
+///
+/// Mirrors [`CastFrom`](crate::CastFrom), but is only implemented for pairs
+/// of integers with identical width (`u32`/`i32`, `u8`/`i8`, and so on).
+///
+/// # Example
+///
+/// ```
+/// use casting::SignCast;
+///
+/// let x: u32 = u32::MAX;
+/// let y = i32::sign_cast(x);
+/// assert_eq!(y, -1i32);
+/// ```
+pub trait SignCast<T> {
+    /// Reinterprets `value` from type `T` as `Self`.
+    fn sign_cast(value: T) -> Self;
+}
+
+/// Reinterpret into the opposite-signedness integer of the same width.
+///
+/// Mirrors [`CastInto`](crate::CastInto) but for [`SignCast`]. Automatically
+/// implemented for all types that implement [`SignCast`].
+///
+/// **Do not implement this trait directly.** Implement [`SignCast`] instead.
+///
+/// # Example
+///
+/// ```
+/// use casting::SignCastInto;
+///
+/// let x: u32 = u32::MAX;
+/// let y: i32 = x.sign_cast_into();
+/// assert_eq!(y, -1i32);
+/// ```
+pub trait SignCastInto<T> {
+    /// Reinterprets `self` into type `T`.
+    fn sign_cast_into(self) -> T;
+}
+
+impl<T, U: SignCast<T>> SignCastInto<U> for T {
+    fn sign_cast_into(self) -> U {
+        U::sign_cast(self)
+    }
+}
+
+macro_rules! impl_grow {
+    ($from:ty => $($into:ty),+ $(,)?) => {
+        $(
+            impl GrowFrom<$from> for $into {
+                #[inline(always)]
+                fn grow_from(value: $from) -> Self {
+                    value as $into
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_trim {
+    ($from:ty => $($into:ty),+ $(,)?) => {
+        $(
+            impl TrimFrom<$from> for $into {
+                #[inline(always)]
+                fn trim_from(value: $from) -> Self {
+                    value as $into
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_sign_cast {
+    ($($unsigned:ty, $signed:ty);+ $(;)?) => {
+        $(
+            impl SignCast<$unsigned> for $signed {
+                #[inline(always)]
+                fn sign_cast(value: $unsigned) -> Self {
+                    value as $signed
+                }
+            }
+
+            impl SignCast<$signed> for $unsigned {
+                #[inline(always)]
+                fn sign_cast(value: $signed) -> Self {
+                    value as $unsigned
+                }
+            }
+        )+
+    };
+}
+
+// Widening within the same signedness.
+impl_grow!(u8   => u16, u32, u64, u128);
+impl_grow!(u16  => u32, u64, u128);
+impl_grow!(u32  => u64, u128);
+impl_grow!(u64  => u128);
+impl_grow!(i8   => i16, i32, i64, i128);
+impl_grow!(i16  => i32, i64, i128);
+impl_grow!(i32  => i64, i128);
+impl_grow!(i64  => i128);
+
+// Unsigned widening into a strictly wider signed integer.
+impl_grow!(u8   => i16, i32, i64, i128);
+impl_grow!(u16  => i32, i64, i128);
+impl_grow!(u32  => i64, i128);
+impl_grow!(u64  => i128);
+
+// Float widening.
+impl_grow!(f32 => f64);
+
+// Narrowing within the same signedness.
+impl_trim!(u16  => u8);
+impl_trim!(u32  => u8, u16);
+impl_trim!(u64  => u8, u16, u32);
+impl_trim!(u128 => u8, u16, u32, u64);
+impl_trim!(i16  => i8);
+impl_trim!(i32  => i8, i16);
+impl_trim!(i64  => i8, i16, i32);
+impl_trim!(i128 => i8, i16, i32, i64);
+
+// Float narrowing.
+impl_trim!(f64 => f32);
+
+impl_sign_cast! {
+    u8, i8;
+    u16, i16;
+    u32, i32;
+    u64, i64;
+    u128, i128;
+    usize, isize;
+}
+
+#[cfg(feature = "nightly")]
+mod nightly_grow_trim {
+    use super::{GrowFrom, TrimFrom};
+
+    impl_grow!(f16 => f32, f64, f128);
+    impl_grow!(f32 => f128);
+    impl_grow!(f64 => f128);
+
+    impl_trim!(f32 => f16);
+    impl_trim!(f64 => f16);
+    impl_trim!(f128 => f16, f32, f64);
+}
@@ -1,12 +1,33 @@
 #![doc = include_str!("../README.md")]
-#![cfg_attr(feature = "nightly", feature(f16, f128))]
-#![no_std]
+#![cfg_attr(feature = "nightly", feature(f16, f128, const_trait_impl))]
+#![cfg_attr(not(test), no_std)]
+
+// `const trait` declarations and `impl const Trait` are nightly-only syntax,
+// and the feature-gate check for them runs before `#[cfg]` stripping, so the
+// `nightly` version of `CastFrom`/`CastInto` (trait declarations included)
+// must live in a file that is only parsed at all (not merely cfg'd out) when
+// `nightly` is enabled.
+#[cfg(feature = "nightly")]
+mod const_cast;
+#[cfg(feature = "nightly")]
+pub use const_cast::{CastFrom, CastInto};
+
+mod grow_trim_sign;
+pub use grow_trim_sign::{GrowFrom, GrowInto, SignCast, SignCastInto, TrimFrom, TrimInto};
+
+mod saturating;
+pub use saturating::{SaturatingCastFrom, SaturatingCastInto};
+
+mod wrapping;
 
 /// Cast from one type to another.
 ///
 /// Mirrors [`From`] but with casting semantics. `CastFrom<T> for U` means
 /// "U can be created from T via cast".
 ///
+/// Under the `nightly` feature, this trait is a `const trait`, so
+/// `cast_from` can be called from a `const fn`.
+///
 /// # Example
 ///
 /// ```
@@ -16,6 +37,7 @@
 /// let y = u16::cast_from(x);
 /// assert_eq!(y, 42u16);
 /// ```
+#[cfg(not(feature = "nightly"))]
 pub trait CastFrom<T> {
     /// Casts `value` from type `T` to `Self`.
     ///
@@ -23,6 +45,7 @@ pub trait CastFrom<T> {
     fn cast_from(value: T) -> Self;
 }
 
+#[cfg(not(feature = "nightly"))]
 impl<T> CastFrom<T> for T {
     fn cast_from(value: T) -> Self {
         value
@@ -36,6 +59,9 @@ impl<T> CastFrom<T> for T {
 ///
 /// **Do not implement this trait directly.** Implement [`CastFrom`] instead.
 ///
+/// Under the `nightly` feature, this trait is a `const trait`, so
+/// `cast_into` can be called from a `const fn`.
+///
 /// # Example
 ///
 /// ```
@@ -45,6 +71,7 @@ impl<T> CastFrom<T> for T {
 /// let y: u16 = x.cast_into();
 /// assert_eq!(y, 42u16);
 /// ```
+#[cfg(not(feature = "nightly"))]
 pub trait CastInto<T> {
     /// Casts `self` into type `T`.
     ///
@@ -52,6 +79,7 @@ pub trait CastInto<T> {
     fn cast_into(self) -> T;
 }
 
+#[cfg(not(feature = "nightly"))]
 impl<T, U: CastFrom<T>> CastInto<U> for T {
     fn cast_into(self) -> U {
         U::cast_from(self)
@@ -64,6 +92,7 @@ macro_rules! impl_cast {
     (@inner $from:ty => ($into:ty))   => { #[cfg(feature = "nightly")] impl_cast! { @inner $from => $into } };
 
     (@inner $from:ty => $into:ty) => {
+        #[cfg(not(feature = "nightly"))]
         impl CastFrom<$from> for $into {
             #[inline(always)]
             fn cast_from(value: $from) -> Self {
@@ -102,3 +131,353 @@ impl_cast! {
     f64    =>  u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, (f16), f32,      (f128);
     (f128) =>  u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, (f16), f32, f64;
 }
+
+/// The reason a [`TryCastFrom`]/[`TryCastInto`] conversion failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The source value is too large to fit in the target type.
+    Overflow,
+
+    /// The source value is too small (too negative) to fit in the target type.
+    Underflow,
+
+    /// The source value is infinite and the target type has no representation for it.
+    Infinite,
+
+    /// The source value is NaN and the target type has no representation for it.
+    NaN,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Overflow => write!(f, "value overflows the target type"),
+            Error::Underflow => write!(f, "value underflows the target type"),
+            Error::Infinite => write!(f, "value is infinite"),
+            Error::NaN => write!(f, "value is NaN"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Fallibly cast from one type to another.
+///
+/// Mirrors [`CastFrom`], but reports when the value falls outside the target's
+/// range (overflow or underflow) or is a NaN or infinite float going to an
+/// integer, instead of silently wrapping or clamping it. In-range conversions
+/// that lose precision still succeed with the `as` result: float to integer
+/// truncates the fraction, and conversions to a float round to the nearest
+/// representable value.
+///
+/// # Example
+///
+/// ```
+/// use casting::{Error, TryCastFrom};
+///
+/// assert_eq!(u8::try_cast_from(42u16), Ok(42u8));
+/// assert_eq!(u8::try_cast_from(256u16), Err(Error::Overflow));
+/// ```
+pub trait TryCastFrom<T> {
+    /// Tries to cast `value` from type `T` to `Self`.
+    ///
+    /// Returns `Err` if `value` is outside the range of `Self`, or is NaN or
+    /// infinite when `Self` is an integer; a loss of precision alone is not
+    /// an error.
+    fn try_cast_from(value: T) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+impl<T> TryCastFrom<T> for T {
+    fn try_cast_from(value: T) -> Result<Self, Error> {
+        Ok(value)
+    }
+}
+
+/// Fallibly cast into another type.
+///
+/// Mirrors [`CastInto`] but for [`TryCastFrom`]. Automatically implemented
+/// for all types that implement [`TryCastFrom`].
+///
+/// **Do not implement this trait directly.** Implement [`TryCastFrom`] instead.
+///
+/// # Example
+///
+/// ```
+/// use casting::{Error, TryCastInto};
+///
+/// let x: Result<u8, Error> = 42u16.try_cast_into();
+/// assert_eq!(x, Ok(42u8));
+/// ```
+pub trait TryCastInto<T> {
+    /// Tries to cast `self` into type `T`.
+    fn try_cast_into(self) -> Result<T, Error>;
+}
+
+impl<T, U: TryCastFrom<T>> TryCastInto<U> for T {
+    fn try_cast_into(self) -> Result<U, Error> {
+        U::try_cast_from(self)
+    }
+}
+
+// Integer to integer: out-of-range values are `Overflow` when the source is
+// positive and `Underflow` when it is negative (unsigned sources can only
+// overflow, since their minimum value of `0` is always in range).
+macro_rules! try_cast_int_to_int {
+    ($from:ty => $($into:ty),+ $(,)?) => {
+        $(
+            impl TryCastFrom<$from> for $into {
+                fn try_cast_from(value: $from) -> Result<Self, Error> {
+                    <$into as core::convert::TryFrom<$from>>::try_from(value).map_err(|_| {
+                        if value > 0 {
+                            Error::Overflow
+                        } else {
+                            Error::Underflow
+                        }
+                    })
+                }
+            }
+        )+
+    };
+}
+
+// Conversions that can never fail: they just perform the `as` cast and wrap
+// the result in `Ok`.
+macro_rules! try_cast_always_ok {
+    ($from:ty => $($into:ty),+ $(,)?) => {
+        $(
+            impl TryCastFrom<$from> for $into {
+                #[inline(always)]
+                fn try_cast_from(value: $from) -> Result<Self, Error> {
+                    Ok(value as $into)
+                }
+            }
+        )+
+    };
+}
+
+// `char` is always non-negative, so it can only ever overflow the target.
+macro_rules! try_cast_char_to_int {
+    ($($into:ty),+ $(,)?) => {
+        $(
+            impl TryCastFrom<char> for $into {
+                fn try_cast_from(value: char) -> Result<Self, Error> {
+                    <$into as core::convert::TryFrom<u32>>::try_from(value as u32)
+                        .map_err(|_| Error::Overflow)
+                }
+            }
+        )+
+    };
+}
+
+// Float to integer: NaN and infinities have no integer representation, and a
+// finite value outside the target's range over/underflows it.
+//
+// The bounds below are *not* `Self::MIN as $from`/`Self::MAX as $from`: for
+// any target wider than the float's mantissa (e.g. `i64` against `f64`),
+// casting `Self::MAX` to the float type rounds up past the true boundary
+// (`i64::MAX as f64` rounds to `2^63`, which overflows `i64`), so that
+// comparison silently accepts out-of-range values. The bounds are computed
+// from powers of two instead: each is exactly representable in the float
+// type, or, when it exceeds the float's range (`2^128` as `f32`), becomes
+// infinity, which still bounds every finite value correctly.
+macro_rules! try_cast_float_to_int {
+    ($from:ty => $($into:ty),+ $(,)?) => {
+        $(
+            impl TryCastFrom<$from> for $into {
+                fn try_cast_from(value: $from) -> Result<Self, Error> {
+                    if value.is_nan() {
+                        return Err(Error::NaN);
+                    }
+                    if value.is_infinite() {
+                        return Err(Error::Infinite);
+                    }
+
+                    let signed = Self::MIN != 0;
+                    let min = if signed {
+                        -((1u128 << (Self::BITS - 1)) as $from)
+                    } else {
+                        0.0
+                    };
+                    let max_exclusive = if signed {
+                        (1u128 << (Self::BITS - 1)) as $from
+                    } else if Self::BITS == 128 {
+                        (1u128 << 127) as $from * 2.0
+                    } else {
+                        (1u128 << Self::BITS) as $from
+                    };
+
+                    if value < min {
+                        Err(Error::Underflow)
+                    } else if value >= max_exclusive {
+                        Err(Error::Overflow)
+                    } else {
+                        Ok(value as $into)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+// Float to float narrowing: only a finite value that rounds to infinity
+// fails; NaN narrows to NaN and infinities narrow to infinities just fine.
+macro_rules! try_cast_float_to_float_narrowing {
+    ($from:ty => $($into:ty),+ $(,)?) => {
+        $(
+            impl TryCastFrom<$from> for $into {
+                fn try_cast_from(value: $from) -> Result<Self, Error> {
+                    let narrowed = value as $into;
+                    if narrowed.is_infinite() && !value.is_infinite() {
+                        if value > 0.0 {
+                            Err(Error::Overflow)
+                        } else {
+                            Err(Error::Underflow)
+                        }
+                    } else {
+                        Ok(narrowed)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+try_cast_always_ok!(bool => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+try_cast_char_to_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl TryCastFrom<u8> for char {
+    fn try_cast_from(value: u8) -> Result<Self, Error> {
+        Ok(char::from(value))
+    }
+}
+
+try_cast_int_to_int!(u8    => u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+try_cast_int_to_int!(u16   => u8, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+try_cast_int_to_int!(u32   => u8, u16, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+try_cast_int_to_int!(u64   => u8, u16, u32, u128, usize, i8, i16, i32, i64, i128, isize);
+try_cast_int_to_int!(u128  => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+try_cast_int_to_int!(usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize);
+try_cast_int_to_int!(i8    => u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+try_cast_int_to_int!(i16   => u8, u16, u32, u64, u128, usize, i8, i32, i64, i128, isize);
+try_cast_int_to_int!(i32   => u8, u16, u32, u64, u128, usize, i8, i16, i64, i128, isize);
+try_cast_int_to_int!(i64   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i128, isize);
+try_cast_int_to_int!(i128  => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+try_cast_int_to_int!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128);
+
+try_cast_always_ok!(u8    => f32, f64);
+try_cast_always_ok!(u16   => f32, f64);
+try_cast_always_ok!(u32   => f32, f64);
+try_cast_always_ok!(u64   => f32, f64);
+try_cast_always_ok!(u128  => f32, f64);
+try_cast_always_ok!(usize => f32, f64);
+try_cast_always_ok!(i8    => f32, f64);
+try_cast_always_ok!(i16   => f32, f64);
+try_cast_always_ok!(i32   => f32, f64);
+try_cast_always_ok!(i64   => f32, f64);
+try_cast_always_ok!(i128  => f32, f64);
+try_cast_always_ok!(isize => f32, f64);
+
+try_cast_float_to_int!(f32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+try_cast_float_to_int!(f64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+try_cast_always_ok!(f32 => f64);
+try_cast_float_to_float_narrowing!(f64 => f32);
+
+#[cfg(feature = "nightly")]
+mod nightly_try_cast {
+    use super::{Error, TryCastFrom};
+
+    try_cast_always_ok!(u8    => f16, f128);
+    try_cast_always_ok!(u16   => f16, f128);
+    try_cast_always_ok!(u32   => f16, f128);
+    try_cast_always_ok!(u64   => f16, f128);
+    try_cast_always_ok!(u128  => f16, f128);
+    try_cast_always_ok!(usize => f16, f128);
+    try_cast_always_ok!(i8    => f16, f128);
+    try_cast_always_ok!(i16   => f16, f128);
+    try_cast_always_ok!(i32   => f16, f128);
+    try_cast_always_ok!(i64   => f16, f128);
+    try_cast_always_ok!(i128  => f16, f128);
+    try_cast_always_ok!(isize => f16, f128);
+
+    try_cast_float_to_int!(f16  => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    try_cast_float_to_int!(f128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    try_cast_always_ok!(f16 => f32, f64, f128);
+    try_cast_float_to_float_narrowing!(f32 => f16);
+    try_cast_always_ok!(f64 => f128);
+    try_cast_float_to_float_narrowing!(f64 => f16);
+    try_cast_float_to_float_narrowing!(f128 => f16, f32, f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int_to_int_boundaries() {
+        assert_eq!(u8::try_cast_from(255u16), Ok(255u8));
+        assert_eq!(u8::try_cast_from(256u16), Err(Error::Overflow));
+        assert_eq!(i8::try_cast_from(127i16), Ok(127i8));
+        assert_eq!(i8::try_cast_from(128i16), Err(Error::Overflow));
+        assert_eq!(i8::try_cast_from(-128i16), Ok(-128i8));
+        assert_eq!(i8::try_cast_from(-129i16), Err(Error::Underflow));
+        assert_eq!(u8::try_cast_from(-1i16), Err(Error::Underflow));
+    }
+
+    #[test]
+    fn char_boundaries() {
+        assert_eq!(u8::try_cast_from('\u{ff}'), Ok(0xffu8));
+        assert_eq!(u8::try_cast_from('\u{100}'), Err(Error::Overflow));
+        assert_eq!(char::try_cast_from(0x41u8), Ok('A'));
+    }
+
+    #[test]
+    fn float_to_int_exact_power_of_two_boundary() {
+        // Regression test: `i64::MAX as f64` rounds up to `2^63`, which is
+        // one past the true boundary, so comparing against it directly
+        // would let this overflowing value through as `Ok`.
+        assert_eq!(i64::try_cast_from(9223372036854775808.0_f64), Err(Error::Overflow));
+        // The same rounding affects any target wider than the float's
+        // mantissa; `f32`'s 23-bit mantissa can't represent `i32::MAX`
+        // exactly either.
+        assert_eq!(i32::try_cast_from(2147483648.0_f32), Err(Error::Overflow));
+
+        // `Self::MIN` is a power of two for every signed integer type, so it
+        // is exactly representable and must still be accepted.
+        assert_eq!(i64::try_cast_from(-9223372036854775808.0_f64), Ok(i64::MIN));
+        assert_eq!(i32::try_cast_from(-2147483648.0_f32), Ok(i32::MIN));
+
+        // `u64::MAX`/`u128::MAX` have the same rounding issue on the
+        // unsigned side.
+        assert_eq!(u64::try_cast_from(18446744073709551616.0_f64), Err(Error::Overflow));
+        assert_eq!(
+            u128::try_cast_from(340282366920938463463374607431768211456.0_f64),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn float_to_int_nan_and_infinite() {
+        assert_eq!(i32::try_cast_from(f32::NAN), Err(Error::NaN));
+        assert_eq!(i32::try_cast_from(f32::INFINITY), Err(Error::Infinite));
+        assert_eq!(i32::try_cast_from(f32::NEG_INFINITY), Err(Error::Infinite));
+    }
+
+    #[test]
+    fn float_to_int_in_range() {
+        assert_eq!(u8::try_cast_from(255.9_f32), Ok(255u8));
+        assert_eq!(i32::try_cast_from(-1.5_f32), Ok(-1i32));
+    }
+
+    #[test]
+    fn float_to_float_narrowing() {
+        assert_eq!(f32::try_cast_from(f64::MAX), Err(Error::Overflow));
+        assert_eq!(f32::try_cast_from(-f64::MAX), Err(Error::Underflow));
+        assert_eq!(f32::try_cast_from(1.5_f64), Ok(1.5_f32));
+        assert_eq!(f32::try_cast_from(f64::NAN).map(f32::is_nan), Ok(true));
+        assert_eq!(f32::try_cast_from(f64::INFINITY), Ok(f32::INFINITY));
+    }
+}
@@ -0,0 +1,171 @@
+//! A cast that clamps instead of wrapping or truncating.
+
+/// Cast from one type to another, clamping out-of-range values instead of
+/// wrapping or truncating them.
+///
+/// Mirrors [`CastFrom`](crate::CastFrom), but integer conversions that would
+/// overflow or underflow the target saturate to `Self::MAX`/`Self::MIN`
+/// instead. Float-to-integer conversions already saturate under Rust's `as`
+/// semantics (with NaN mapping to `0`), and conversions to a float round to
+/// the nearest value (out-of-range values become `±INFINITY`, NaN stays NaN),
+/// so those are identical to the plain `as` cast.
+///
+/// # Example
+///
+/// ```
+/// use casting::SaturatingCastFrom;
+///
+/// assert_eq!(u8::saturating_cast_from(-1i32), 0u8);
+/// assert_eq!(u8::saturating_cast_from(300i32), u8::MAX);
+/// ```
+pub trait SaturatingCastFrom<T> {
+    /// Casts `value` from type `T` to `Self`, clamping out-of-range values.
+    fn saturating_cast_from(value: T) -> Self;
+}
+
+/// Cast into another type, clamping out-of-range values instead of wrapping
+/// or truncating them.
+///
+/// Mirrors [`CastInto`](crate::CastInto) but for [`SaturatingCastFrom`].
+/// Automatically implemented for all types that implement
+/// [`SaturatingCastFrom`].
+///
+/// **Do not implement this trait directly.** Implement
+/// [`SaturatingCastFrom`] instead.
+///
+/// # Example
+///
+/// ```
+/// use casting::SaturatingCastInto;
+///
+/// let y: u8 = 300i32.saturating_cast_into();
+/// assert_eq!(y, u8::MAX);
+/// ```
+pub trait SaturatingCastInto<T> {
+    /// Casts `self` into type `T`, clamping out-of-range values.
+    fn saturating_cast_into(self) -> T;
+}
+
+impl<T, U: SaturatingCastFrom<T>> SaturatingCastInto<U> for T {
+    fn saturating_cast_into(self) -> U {
+        U::saturating_cast_from(self)
+    }
+}
+
+impl<T> SaturatingCastFrom<T> for T {
+    fn saturating_cast_from(value: T) -> Self {
+        value
+    }
+}
+
+// Integer to integer: clamp to the target's range instead of wrapping.
+macro_rules! saturating_cast_int_to_int {
+    ($from:ty => $($into:ty),+ $(,)?) => {
+        $(
+            impl SaturatingCastFrom<$from> for $into {
+                fn saturating_cast_from(value: $from) -> Self {
+                    <$into as core::convert::TryFrom<$from>>::try_from(value)
+                        .unwrap_or(if value > 0 { Self::MAX } else { Self::MIN })
+                }
+            }
+        )+
+    };
+}
+
+// `char` is always non-negative, so out-of-range values can only saturate up.
+macro_rules! saturating_cast_char_to_int {
+    ($($into:ty),+ $(,)?) => {
+        $(
+            impl SaturatingCastFrom<char> for $into {
+                fn saturating_cast_from(value: char) -> Self {
+                    <$into as core::convert::TryFrom<u32>>::try_from(value as u32)
+                        .unwrap_or(Self::MAX)
+                }
+            }
+        )+
+    };
+}
+
+// Every other conversion in `impl_cast!`'s table (bool to int, int to float,
+// float to int, and float to float) is used as-is: `as` saturates float to
+// int and rounds to the nearest value (or `±INFINITY`) going to a float.
+macro_rules! saturating_cast_as_is {
+    ($from:ty => $($into:ty),+ $(,)?) => {
+        $(
+            impl SaturatingCastFrom<$from> for $into {
+                #[inline(always)]
+                fn saturating_cast_from(value: $from) -> Self {
+                    value as $into
+                }
+            }
+        )+
+    };
+}
+
+saturating_cast_as_is!(bool => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+saturating_cast_char_to_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl SaturatingCastFrom<u8> for char {
+    fn saturating_cast_from(value: u8) -> Self {
+        char::from(value)
+    }
+}
+
+saturating_cast_int_to_int!(u8    => u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+saturating_cast_int_to_int!(u16   => u8, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+saturating_cast_int_to_int!(u32   => u8, u16, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+saturating_cast_int_to_int!(u64   => u8, u16, u32, u128, usize, i8, i16, i32, i64, i128, isize);
+saturating_cast_int_to_int!(u128  => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+saturating_cast_int_to_int!(usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize);
+saturating_cast_int_to_int!(i8    => u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+saturating_cast_int_to_int!(i16   => u8, u16, u32, u64, u128, usize, i8, i32, i64, i128, isize);
+saturating_cast_int_to_int!(i32   => u8, u16, u32, u64, u128, usize, i8, i16, i64, i128, isize);
+saturating_cast_int_to_int!(i64   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i128, isize);
+saturating_cast_int_to_int!(i128  => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+saturating_cast_int_to_int!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128);
+
+saturating_cast_as_is!(u8    => f32, f64);
+saturating_cast_as_is!(u16   => f32, f64);
+saturating_cast_as_is!(u32   => f32, f64);
+saturating_cast_as_is!(u64   => f32, f64);
+saturating_cast_as_is!(u128  => f32, f64);
+saturating_cast_as_is!(usize => f32, f64);
+saturating_cast_as_is!(i8    => f32, f64);
+saturating_cast_as_is!(i16   => f32, f64);
+saturating_cast_as_is!(i32   => f32, f64);
+saturating_cast_as_is!(i64   => f32, f64);
+saturating_cast_as_is!(i128  => f32, f64);
+saturating_cast_as_is!(isize => f32, f64);
+
+saturating_cast_as_is!(f32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+saturating_cast_as_is!(f64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+saturating_cast_as_is!(f32 => f64);
+saturating_cast_as_is!(f64 => f32);
+
+#[cfg(feature = "nightly")]
+mod nightly_saturating {
+    use super::SaturatingCastFrom;
+
+    saturating_cast_as_is!(u8    => f16, f128);
+    saturating_cast_as_is!(u16   => f16, f128);
+    saturating_cast_as_is!(u32   => f16, f128);
+    saturating_cast_as_is!(u64   => f16, f128);
+    saturating_cast_as_is!(u128  => f16, f128);
+    saturating_cast_as_is!(usize => f16, f128);
+    saturating_cast_as_is!(i8    => f16, f128);
+    saturating_cast_as_is!(i16   => f16, f128);
+    saturating_cast_as_is!(i32   => f16, f128);
+    saturating_cast_as_is!(i64   => f16, f128);
+    saturating_cast_as_is!(i128  => f16, f128);
+    saturating_cast_as_is!(isize => f16, f128);
+
+    saturating_cast_as_is!(f16  => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    saturating_cast_as_is!(f128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    saturating_cast_as_is!(f16 => f32, f64, f128);
+    saturating_cast_as_is!(f32 => f16);
+    saturating_cast_as_is!(f64 => f128);
+    saturating_cast_as_is!(f64 => f16);
+    saturating_cast_as_is!(f128 => f16, f32, f64);
+}
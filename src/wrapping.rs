@@ -0,0 +1,134 @@
+//! [`CastFrom`]/[`CastInto`] impls for [`Wrapping`], so code that works with
+//! `Wrapping<u32>` and friends doesn't have to unwrap, cast, and re-wrap by
+//! hand.
+//!
+//! These are written as concrete impls over the same from/into table as the
+//! crate's `impl_cast!` macro, rather than as a blanket `impl<T, U:
+//! CastFrom<T>> CastFrom<Wrapping<T>> for U`, because a fully generic
+//! blanket like that overlaps (and fails to compile) with the crate's
+//! existing `impl<T> CastFrom<T> for T`.
+//!
+//! # Examples
+//!
+//! ```
+//! use casting::CastFrom;
+//! use core::num::Wrapping;
+//!
+//! // `Wrapping<T> -> U`
+//! assert_eq!(u8::cast_from(Wrapping(-1i8)), 255u8);
+//!
+//! // `T -> Wrapping<U>`
+//! assert_eq!(Wrapping::<u8>::cast_from(-1i8), Wrapping(255u8));
+//!
+//! // `Wrapping<T> -> Wrapping<U>`
+//! assert_eq!(Wrapping::<u8>::cast_from(Wrapping(-1i8)), Wrapping(255u8));
+//! ```
+
+use core::num::Wrapping;
+
+use crate::CastFrom;
+
+macro_rules! impl_wrapping_identity {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl CastFrom<Wrapping<$ty>> for $ty {
+                #[inline(always)]
+                fn cast_from(value: Wrapping<$ty>) -> Self {
+                    value.0
+                }
+            }
+
+            impl CastFrom<$ty> for Wrapping<$ty> {
+                #[inline(always)]
+                fn cast_from(value: $ty) -> Self {
+                    Wrapping(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_wrapping_identity!(
+    bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+macro_rules! impl_wrapping_cast {
+    (@inner $from:ty => $into:ty) => {
+        impl CastFrom<Wrapping<$from>> for $into {
+            #[inline(always)]
+            fn cast_from(value: Wrapping<$from>) -> Self {
+                value.0 as $into
+            }
+        }
+
+        impl CastFrom<$from> for Wrapping<$into> {
+            #[inline(always)]
+            fn cast_from(value: $from) -> Self {
+                Wrapping(value as $into)
+            }
+        }
+
+        impl CastFrom<Wrapping<$from>> for Wrapping<$into> {
+            #[inline(always)]
+            fn cast_from(value: Wrapping<$from>) -> Self {
+                Wrapping(value.0 as $into)
+            }
+        }
+    };
+
+    // Entry point
+    ($($from:tt => $($into:tt),+;)+ $(,)?) => {
+        $(
+            $(
+                impl_wrapping_cast! { @inner $from => $into }
+            )+
+        )+
+    };
+}
+
+impl_wrapping_cast! {
+    bool   =>  u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    char   =>  u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    u8     =>      u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, char;
+    u16    =>  u8,      u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
+    u32    =>  u8, u16,      u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
+    u64    =>  u8, u16, u32,      u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
+    u128   =>  u8, u16, u32, u64,       usize, i8, i16, i32, i64, i128, isize, f32, f64;
+    usize  =>  u8, u16, u32, u64, u128,        i8, i16, i32, i64, i128, isize, f32, f64;
+    i8     =>  u8, u16, u32, u64, u128, usize,     i16, i32, i64, i128, isize, f32, f64;
+    i16    =>  u8, u16, u32, u64, u128, usize, i8,      i32, i64, i128, isize, f32, f64;
+    i32    =>  u8, u16, u32, u64, u128, usize, i8, i16,      i64, i128, isize, f32, f64;
+    i64    =>  u8, u16, u32, u64, u128, usize, i8, i16, i32,      i128, isize, f32, f64;
+    i128   =>  u8, u16, u32, u64, u128, usize, i8, i16, i32, i64,       isize, f32, f64;
+    isize  =>  u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128,        f32, f64;
+    f32    =>  u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,      f64;
+    f64    =>  u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32;
+}
+
+#[cfg(feature = "nightly")]
+mod nightly_wrapping {
+    use core::num::Wrapping;
+
+    use crate::CastFrom;
+
+    impl_wrapping_identity!(f16, f128);
+
+    impl_wrapping_cast! {
+        u8     => f16, f128;
+        u16    => f16, f128;
+        u32    => f16, f128;
+        u64    => f16, f128;
+        u128   => f16, f128;
+        usize  => f16, f128;
+        i8     => f16, f128;
+        i16    => f16, f128;
+        i32    => f16, f128;
+        i64    => f16, f128;
+        i128   => f16, f128;
+        isize  => f16, f128;
+        f16    => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, f128;
+        f32    => f16, f128;
+        f64    => f16, f128;
+        f128   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f16, f32, f64;
+    }
+}